@@ -4,33 +4,113 @@
 //! Optimized for M3 MacBooks with no external process spawning.
 
 use objc2_core_audio::{
-    kAudioDevicePropertyMute, kAudioDevicePropertyVolumeScalar,
-    kAudioHardwarePropertyDefaultOutputDevice, kAudioObjectPropertyScopeOutput,
-    AudioObjectGetPropertyData, AudioObjectPropertyAddress, AudioObjectSetPropertyData,
+    kAudioDevicePropertyDeviceNameCFString, kAudioDevicePropertyMute,
+    kAudioDevicePropertyStreamConfiguration, kAudioDevicePropertyVolumeScalar,
+    kAudioHardwarePropertyDefaultInputDevice, kAudioHardwarePropertyDefaultOutputDevice,
+    kAudioHardwarePropertyDevices, kAudioObjectPropertyScopeGlobal,
+    kAudioObjectPropertyScopeInput, kAudioObjectPropertyScopeOutput, AudioBufferList,
+    AudioObjectAddPropertyListener, AudioObjectGetPropertyData, AudioObjectGetPropertyDataSize,
+    AudioObjectPropertyAddress, AudioObjectRemovePropertyListener, AudioObjectSetPropertyData,
 };
+use libc::{c_int, signal, SIGINT, SIGTERM};
+use objc2_core_foundation::{kCFStringEncodingUTF8, CFRelease, CFStringGetCString, CFStringRef};
 use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 /// System object ID for default audio device queries
 const SYSTEM_OBJECT: u32 = 1;
 
-/// Property address for retrieving the default output device
-static DEVICE_ADDRESS: AudioObjectPropertyAddress = AudioObjectPropertyAddress {
-    mSelector: kAudioHardwarePropertyDefaultOutputDevice,
-    mScope: kAudioObjectPropertyScopeOutput,
-    mElement: 0,
-};
+/// Target volume scalar for `watch` mode, shared with the property listener
+/// callback via the bit pattern of an `f32` (there is no stable atomic f32).
+static WATCH_TARGET_VOLUME_BITS: AtomicU32 = AtomicU32::new(0);
+
+/// Scope being watched, shared with the property listener callback (which,
+/// as a bare `extern "C"` function, cannot capture it directly).
+static WATCH_SCOPE_IS_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// Set to `false` by `handle_watch_interrupt` on SIGINT/SIGTERM to break the
+/// `watch` loop so its listener cleanup actually runs.
+static WATCH_RUNNING: AtomicBool = AtomicBool::new(true);
+
+/// Which direction of audio a device property applies to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scope {
+    Input,
+    Output,
+}
+
+impl Scope {
+    /// The Core Audio scope constant for this `Scope`.
+    fn core_audio_scope(self) -> u32 {
+        match self {
+            Scope::Input => kAudioObjectPropertyScopeInput,
+            Scope::Output => kAudioObjectPropertyScopeOutput,
+        }
+    }
+
+    /// The hardware property selector for the default device in this `Scope`.
+    fn default_device_selector(self) -> u32 {
+        match self {
+            Scope::Input => kAudioHardwarePropertyDefaultInputDevice,
+            Scope::Output => kAudioHardwarePropertyDefaultOutputDevice,
+        }
+    }
+}
 
-/// Property address for setting master volume on a device
-static VOLUME_ADDRESS: AudioObjectPropertyAddress = AudioObjectPropertyAddress {
-    mSelector: kAudioDevicePropertyVolumeScalar,
-    mScope: kAudioObjectPropertyScopeOutput,
+/// Property address for retrieving the default device in the given scope.
+fn device_address(scope: Scope) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: scope.default_device_selector(),
+        mScope: scope.core_audio_scope(),
+        mElement: 0,
+    }
+}
+
+/// Property address for setting master volume on a device in the given scope.
+fn volume_address(scope: Scope) -> AudioObjectPropertyAddress {
+    volume_address_element(scope, 0)
+}
+
+/// Property address for the volume scalar on a specific channel element
+/// (0 = master, 1..=N = individual channels).
+fn volume_address_element(scope: Scope, element: u32) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: kAudioDevicePropertyVolumeScalar,
+        mScope: scope.core_audio_scope(),
+        mElement: element,
+    }
+}
+
+/// Property address for a device's stream configuration (channel layout) in
+/// the given scope.
+fn stream_configuration_address(scope: Scope) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: kAudioDevicePropertyStreamConfiguration,
+        mScope: scope.core_audio_scope(),
+        mElement: 0,
+    }
+}
+
+/// Property address for mute control on a device in the given scope.
+fn mute_address(scope: Scope) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: kAudioDevicePropertyMute,
+        mScope: scope.core_audio_scope(),
+        mElement: 0,
+    }
+}
+
+/// Property address for enumerating all audio devices on the system object
+static DEVICES_ADDRESS: AudioObjectPropertyAddress = AudioObjectPropertyAddress {
+    mSelector: kAudioHardwarePropertyDevices,
+    mScope: kAudioObjectPropertyScopeGlobal,
     mElement: 0,
 };
 
-/// Property address for mute control
-static MUTE_ADDRESS: AudioObjectPropertyAddress = AudioObjectPropertyAddress {
-    mSelector: kAudioDevicePropertyMute,
-    mScope: kAudioObjectPropertyScopeOutput,
+/// Property address for reading a device's display name
+static DEVICE_NAME_ADDRESS: AudioObjectPropertyAddress = AudioObjectPropertyAddress {
+    mSelector: kAudioDevicePropertyDeviceNameCFString,
+    mScope: kAudioObjectPropertyScopeGlobal,
     mElement: 0,
 };
 
@@ -69,20 +149,50 @@ fn parse_volume(input: &str) -> Result<f32, VolumeError> {
     Ok(percent / 100.0)
 }
 
-/// Retrieves the default audio output device ID.
+/// A volume argument: either an absolute target or a relative nudge.
+#[derive(Debug, PartialEq)]
+enum VolumeArg {
+    /// Absolute target scalar (0.0-1.0), e.g. from `50`
+    Absolute(f32),
+    /// Relative delta scalar, e.g. from `+5` or `-10`
+    Relative(f32),
+}
+
+/// Parses a volume argument, treating a leading `+`/`-` as a relative delta
+/// and anything else as an absolute target (see `parse_volume`).
+fn parse_volume_arg(input: &str) -> Result<VolumeArg, VolumeError> {
+    if let Some(rest) = input.strip_prefix('+') {
+        let delta: f32 = rest
+            .parse()
+            .map_err(|_| VolumeError::InvalidInput("Invalid number"))?;
+        return Ok(VolumeArg::Relative(delta / 100.0));
+    }
+
+    if input.starts_with('-') {
+        let delta: f32 = input
+            .parse()
+            .map_err(|_| VolumeError::InvalidInput("Invalid number"))?;
+        return Ok(VolumeArg::Relative(delta / 100.0));
+    }
+
+    parse_volume(input).map(VolumeArg::Absolute)
+}
+
+/// Retrieves the default audio device ID for the given scope.
 ///
 /// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
 /// - All pointers are valid references to properly aligned stack variables
 /// - The system object (ID 1) always exists on macOS
 /// - The operation is read-only and thread-safe
-fn get_default_device() -> Result<u32, VolumeError> {
+fn get_default_device(scope: Scope) -> Result<u32, VolumeError> {
+    let address = device_address(scope);
     let mut device_id: u32 = 0;
     let mut data_size: u32 = std::mem::size_of::<u32>() as u32;
 
     let status = unsafe {
         AudioObjectGetPropertyData(
             SYSTEM_OBJECT,
-            std::ptr::NonNull::new_unchecked(&raw const DEVICE_ADDRESS as *mut _),
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
             0,
             std::ptr::null(),
             std::ptr::NonNull::new_unchecked(&raw mut data_size),
@@ -97,19 +207,188 @@ fn get_default_device() -> Result<u32, VolumeError> {
     }
 }
 
-/// Sets the mute state on the specified audio device.
+/// Converts a `CFStringRef` to an owned UTF-8 `String`, if possible, and
+/// releases the reference.
+///
+/// SAFETY: `cf_string` must be a valid, non-null CFStringRef that the
+/// caller holds a +1 reference to (as returned by
+/// `AudioObjectGetPropertyData`) and will not use again after this call,
+/// since this function releases it via `CFRelease`.
+fn cfstring_to_string(cf_string: CFStringRef) -> Option<String> {
+    let mut buf = [0i8; 256];
+
+    let ok = unsafe {
+        CFStringGetCString(
+            &*cf_string,
+            buf.as_mut_ptr(),
+            buf.len() as isize,
+            kCFStringEncodingUTF8,
+        )
+    };
+
+    unsafe { CFRelease(&*cf_string) };
+
+    if !ok {
+        return None;
+    }
+
+    let cstr = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+    cstr.to_str().ok().map(str::to_owned)
+}
+
+/// Lists every audio device known to the system, as `(device_id, name)` pairs.
+/// Devices whose name cannot be read or converted to UTF-8 are skipped.
+///
+/// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
+/// - All pointers are valid references to properly aligned stack/heap memory
+/// - The system object (ID 1) always exists on macOS
+/// - The operation is read-only and thread-safe
+fn list_devices() -> Result<Vec<(u32, String)>, VolumeError> {
+    let mut data_size: u32 = 0;
+
+    let status = unsafe {
+        AudioObjectGetPropertyDataSize(
+            SYSTEM_OBJECT,
+            std::ptr::NonNull::new_unchecked(&raw const DEVICES_ADDRESS as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut data_size),
+        )
+    };
+
+    if status != 0 {
+        return Err(VolumeError::DeviceError(status));
+    }
+
+    let count = data_size as usize / std::mem::size_of::<u32>();
+    let mut device_ids = vec![0u32; count];
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            SYSTEM_OBJECT,
+            std::ptr::NonNull::new_unchecked(&raw const DEVICES_ADDRESS as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut data_size),
+            std::ptr::NonNull::new_unchecked(device_ids.as_mut_ptr() as *mut _),
+        )
+    };
+
+    if status != 0 {
+        return Err(VolumeError::DeviceError(status));
+    }
+
+    let mut devices = Vec::with_capacity(device_ids.len());
+    for device_id in device_ids {
+        let mut name_ref: CFStringRef = std::ptr::null();
+        let mut name_size: u32 = std::mem::size_of::<CFStringRef>() as u32;
+
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                device_id,
+                std::ptr::NonNull::new_unchecked(&raw const DEVICE_NAME_ADDRESS as *mut _),
+                0,
+                std::ptr::null(),
+                std::ptr::NonNull::new_unchecked(&raw mut name_size),
+                std::ptr::NonNull::new_unchecked(&raw mut name_ref as *mut _),
+            )
+        };
+
+        if status != 0 || name_ref.is_null() {
+            continue;
+        }
+
+        if let Some(name) = cfstring_to_string(name_ref) {
+            devices.push((device_id, name));
+        }
+    }
+
+    Ok(devices)
+}
+
+/// Reads the number of individually addressable channels a device exposes in
+/// the given scope, via its stream configuration.
+///
+/// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
+/// - The returned `AudioBufferList` bytes are backed by a `Vec<u64>`, which
+///   gives the buffer 8-byte alignment — required since `AudioBuffer`
+///   contains a pointer field, and plain `Vec<u8>` only guarantees 1-byte
+///   alignment (dereferencing an under-aligned `*const AudioBufferList`
+///   would be UB)
+/// - The returned `AudioBufferList` is read, never written to or freed by us
+/// - The operation is read-only and thread-safe
+fn channel_count(device_id: u32, scope: Scope) -> Result<u32, VolumeError> {
+    let address = stream_configuration_address(scope);
+    let mut data_size: u32 = 0;
+
+    let status = unsafe {
+        AudioObjectGetPropertyDataSize(
+            device_id,
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut data_size),
+        )
+    };
+
+    if status != 0 {
+        return Err(VolumeError::DeviceError(status));
+    }
+
+    // Back the buffer with u64 words (not u8) so it's properly aligned for
+    // AudioBufferList, whose AudioBuffer entries contain a pointer field.
+    let word_count = (data_size as usize).div_ceil(std::mem::size_of::<u64>()).max(1);
+    let mut buf: Vec<u64> = vec![0u64; word_count];
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device_id,
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut data_size),
+            std::ptr::NonNull::new_unchecked(buf.as_mut_ptr() as *mut _),
+        )
+    };
+
+    if status != 0 {
+        return Err(VolumeError::DeviceError(status));
+    }
+
+    let buffer_list = unsafe { &*(buf.as_ptr() as *const AudioBufferList) };
+    let buffers = unsafe {
+        std::slice::from_raw_parts(
+            buffer_list.mBuffers.as_ptr(),
+            buffer_list.mNumberBuffers as usize,
+        )
+    };
+
+    Ok(buffers.iter().map(|buffer| buffer.mNumberChannels).sum())
+}
+
+/// Finds a device ID by exact (case-sensitive) name match.
+fn find_device_by_name(name: &str) -> Result<Option<u32>, VolumeError> {
+    let devices = list_devices()?;
+    Ok(devices
+        .into_iter()
+        .find(|(_, device_name)| device_name == name)
+        .map(|(device_id, _)| device_id))
+}
+
+/// Sets the mute state on the specified audio device, in the given scope.
 ///
 /// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
 /// - The device_id is validated by a successful call to get_default_device()
 /// - The mute_value pointer points to valid, aligned stack memory
 /// - The size matches exactly what Core Audio expects (u32 for boolean)
-fn set_mute(device_id: u32, muted: bool) -> Result<(), VolumeError> {
+fn set_mute(device_id: u32, muted: bool, scope: Scope) -> Result<(), VolumeError> {
+    let address = mute_address(scope);
     let mute_value: u32 = if muted { 1 } else { 0 };
 
     let status = unsafe {
         AudioObjectSetPropertyData(
             device_id,
-            std::ptr::NonNull::new_unchecked(&raw const MUTE_ADDRESS as *mut _),
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
             0,
             std::ptr::null(),
             std::mem::size_of::<u32>() as u32,
@@ -124,7 +403,104 @@ fn set_mute(device_id: u32, muted: bool) -> Result<(), VolumeError> {
     }
 }
 
-/// Sets the master volume on the specified audio device.
+/// Reads the scalar volume (0.0-1.0) on a single property element.
+fn read_volume_element(device_id: u32, scope: Scope, element: u32) -> Result<f32, i32> {
+    let address = volume_address_element(scope, element);
+    let mut volume: f32 = 0.0;
+    let mut data_size: u32 = std::mem::size_of::<f32>() as u32;
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device_id,
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut data_size),
+            std::ptr::NonNull::new_unchecked(&raw mut volume as *mut _),
+        )
+    };
+
+    if status != 0 {
+        Err(status)
+    } else {
+        Ok(volume)
+    }
+}
+
+/// Reads the current master volume and mute state of the specified audio
+/// device, in the given scope.
+///
+/// Some devices (notably aggregate devices) have no master volume control
+/// (element 0) and only expose volume per channel; when element 0 fails,
+/// this falls back to averaging the scalar across each channel element.
+///
+/// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
+/// - The device_id was returned by Core Audio, via get_default_device() or
+///   find_device_by_name()
+/// - The out-parameter pointers point to valid, aligned stack memory
+/// - The operation is read-only and thread-safe
+fn get_volume(device_id: u32, scope: Scope) -> Result<(f32, bool), VolumeError> {
+    let volume = match read_volume_element(device_id, scope, 0) {
+        Ok(v) => v,
+        Err(master_status) => {
+            let channels = channel_count(device_id, scope)
+                .map_err(|_| VolumeError::DeviceError(master_status))?;
+            let readings: Vec<f32> = (1..=channels)
+                .filter_map(|element| read_volume_element(device_id, scope, element).ok())
+                .collect();
+
+            if readings.is_empty() {
+                return Err(VolumeError::DeviceError(master_status));
+            }
+
+            readings.iter().sum::<f32>() / readings.len() as f32
+        }
+    };
+
+    let mute_addr = mute_address(scope);
+    let mut muted: u32 = 0;
+    let mut mute_size: u32 = std::mem::size_of::<u32>() as u32;
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device_id,
+            std::ptr::NonNull::new_unchecked(&raw const mute_addr as *mut _),
+            0,
+            std::ptr::null(),
+            std::ptr::NonNull::new_unchecked(&raw mut mute_size),
+            std::ptr::NonNull::new_unchecked(&raw mut muted as *mut _),
+        )
+    };
+
+    if status != 0 {
+        return Err(VolumeError::DeviceError(status));
+    }
+
+    Ok((volume, muted != 0))
+}
+
+/// Writes the scalar volume (0.0-1.0) to a single property element.
+fn write_volume_element(device_id: u32, scope: Scope, element: u32, volume: f32) -> i32 {
+    let address = volume_address_element(scope, element);
+
+    unsafe {
+        AudioObjectSetPropertyData(
+            device_id,
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
+            0,
+            std::ptr::null(),
+            std::mem::size_of::<f32>() as u32,
+            std::ptr::NonNull::new_unchecked(&raw const volume as *mut _),
+        )
+    }
+}
+
+/// Sets the master volume on the specified audio device, in the given scope.
+///
+/// Some devices (notably aggregate devices) have no master volume control
+/// (element 0) and only expose volume per channel; when element 0 fails,
+/// this falls back to setting the scalar on each channel element
+/// individually, only surfacing an error if every element fails.
 ///
 /// SAFETY: This function makes FFI calls to Core Audio. It is safe because:
 /// - The device_id is validated by a successful call to get_default_device()
@@ -134,52 +510,254 @@ fn set_mute(device_id: u32, muted: bool) -> Result<(), VolumeError> {
 /// Auto-mute behavior:
 /// - When volume is 0, the device is muted for complete silence
 /// - When volume > 0, the device is unmuted to ensure audio plays
-fn set_volume(device_id: u32, volume: f32) -> Result<(), VolumeError> {
+fn set_volume(device_id: u32, volume: f32, scope: Scope) -> Result<(), VolumeError> {
+    let master_status = write_volume_element(device_id, scope, 0, volume);
+
+    if master_status != 0 {
+        let channels = channel_count(device_id, scope)
+            .map_err(|_| VolumeError::SetError(master_status))?;
+        let any_succeeded = (1..=channels)
+            .map(|element| write_volume_element(device_id, scope, element, volume))
+            .fold(false, |ok, status| ok || status == 0);
+
+        if !any_succeeded {
+            return Err(VolumeError::SetError(master_status));
+        }
+    }
+
+    // Auto-mute when volume is 0 for complete silence
+    // Auto-unmute when volume > 0 to ensure audio plays
+    set_mute(device_id, volume == 0.0, scope)?;
+
+    Ok(())
+}
+
+/// Property listener callback for the default device in the watched scope
+/// (`kAudioHardwarePropertyDefaultOutputDevice` or
+/// `...DefaultInputDevice`, per `WATCH_SCOPE_IS_INPUT`).
+///
+/// Re-resolves the new default device and reapplies the target volume
+/// stashed in `WATCH_TARGET_VOLUME_BITS`.
+///
+/// SAFETY: Core Audio requires this signature for `AudioObjectPropertyListenerProc`.
+/// It touches no caller-provided data beyond the atomics and the FFI calls
+/// made by `get_default_device`/`set_volume`, both of which are safe on their own.
+unsafe extern "C-unwind" fn on_default_device_changed(
+    _object_id: u32,
+    _num_addresses: u32,
+    _addresses: *const AudioObjectPropertyAddress,
+    _client_data: *mut std::ffi::c_void,
+) -> i32 {
+    let volume = f32::from_bits(WATCH_TARGET_VOLUME_BITS.load(Ordering::SeqCst));
+    let scope = if WATCH_SCOPE_IS_INPUT.load(Ordering::SeqCst) {
+        Scope::Input
+    } else {
+        Scope::Output
+    };
+
+    if let Ok(device_id) = get_default_device(scope) {
+        let _ = set_volume(device_id, volume, scope);
+    }
+
+    0
+}
+
+/// Signal handler for SIGINT/SIGTERM during `watch`: breaks the polling loop
+/// so normal function return (and with it, the listener-removal guard) runs
+/// instead of the process dying mid-syscall.
+extern "C" fn handle_watch_interrupt(_signum: c_int) {
+    WATCH_RUNNING.store(false, Ordering::SeqCst);
+}
+
+/// RAII guard that removes the default-device property listener for the
+/// watched scope (output or input) when dropped, pairing with
+/// `AudioObjectAddPropertyListener` in `watch`.
+struct DefaultDeviceListenerGuard {
+    address: AudioObjectPropertyAddress,
+}
+
+impl Drop for DefaultDeviceListenerGuard {
+    fn drop(&mut self) {
+        unsafe {
+            AudioObjectRemovePropertyListener(
+                SYSTEM_OBJECT,
+                std::ptr::NonNull::new_unchecked(&raw const self.address as *const _ as *mut _),
+                Some(on_default_device_changed),
+                std::ptr::null_mut(),
+            );
+        }
+    }
+}
+
+/// Watches for default device changes in the given scope and reapplies
+/// `volume` to the new default whenever macOS hot-swaps it (e.g. headphones
+/// plugged in, or a USB mic connected when `scope` is `Scope::Input`).
+///
+/// Runs until the process is interrupted (e.g. Ctrl-C), at which point the
+/// listener installed below is torn down before returning.
+fn watch(volume: f32, scope: Scope) -> Result<(), VolumeError> {
+    WATCH_TARGET_VOLUME_BITS.store(volume.to_bits(), Ordering::SeqCst);
+    WATCH_SCOPE_IS_INPUT.store(scope == Scope::Input, Ordering::SeqCst);
+
+    let device_id = get_default_device(scope)?;
+    set_volume(device_id, volume, scope)?;
+
+    let address = device_address(scope);
+
     let status = unsafe {
-        AudioObjectSetPropertyData(
-            device_id,
-            std::ptr::NonNull::new_unchecked(&raw const VOLUME_ADDRESS as *mut _),
-            0,
-            std::ptr::null(),
-            std::mem::size_of::<f32>() as u32,
-            std::ptr::NonNull::new_unchecked(&raw const volume as *mut _),
+        AudioObjectAddPropertyListener(
+            SYSTEM_OBJECT,
+            std::ptr::NonNull::new_unchecked(&raw const address as *mut _),
+            Some(on_default_device_changed),
+            std::ptr::null_mut(),
         )
     };
 
     if status != 0 {
-        return Err(VolumeError::SetError(status));
+        return Err(VolumeError::DeviceError(status));
     }
 
-    // Auto-mute when volume is 0 for complete silence
-    // Auto-unmute when volume > 0 to ensure audio plays
-    set_mute(device_id, volume == 0.0)?;
+    let _guard = DefaultDeviceListenerGuard { address };
+
+    WATCH_RUNNING.store(true, Ordering::SeqCst);
+    unsafe {
+        signal(SIGINT, handle_watch_interrupt as usize);
+        signal(SIGTERM, handle_watch_interrupt as usize);
+    }
+
+    while WATCH_RUNNING.load(Ordering::SeqCst) {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
 
     Ok(())
 }
 
 fn main() {
-    let input = match std::env::args().nth(1) {
-        Some(arg) => arg,
-        None => return, // No-op if no arguments provided
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let scope = match args.iter().position(|arg| arg == "--input") {
+        Some(idx) => {
+            args.remove(idx);
+            Scope::Input
+        }
+        None => Scope::Output,
     };
 
-    let volume = match parse_volume(&input) {
-        Ok(v) => v,
-        Err(e) => {
+    let device_name = match args.iter().position(|arg| arg == "--device") {
+        Some(idx) => {
+            args.remove(idx);
+            if idx >= args.len() {
+                eprintln!("--device requires a name");
+                std::process::exit(1);
+            }
+            Some(args.remove(idx))
+        }
+        None => None,
+    };
+
+    if args.is_empty() {
+        return; // No-op if no arguments provided
+    }
+    let input = args.remove(0);
+
+    if input == "watch" {
+        if device_name.is_some() {
+            eprintln!("watch does not support --device; it always tracks the system default");
+            std::process::exit(1);
+        }
+
+        let percent_input = if args.is_empty() {
+            eprintln!("watch requires a target percent");
+            std::process::exit(1);
+        } else {
+            args.remove(0)
+        };
+
+        let volume = match parse_volume(&percent_input) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if let Err(e) = watch(volume, scope) {
             eprintln!("{}", e);
             std::process::exit(1);
         }
+        return;
+    }
+
+    if input == "list" {
+        match list_devices() {
+            Ok(devices) => {
+                for (device_id, name) in devices {
+                    println!("{}\t{}", device_id, name);
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let device_id = match &device_name {
+        Some(name) => match find_device_by_name(name) {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                eprintln!("No such device: {}", name);
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        None => match get_default_device(scope) {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
     };
 
-    let device_id = match get_default_device() {
-        Ok(id) => id,
+    if input == "get" {
+        match get_volume(device_id, scope) {
+            Ok((volume, muted)) => {
+                let percent = (volume * 100.0).round() as i32;
+                if muted {
+                    println!("{} (muted)", percent);
+                } else {
+                    println!("{}", percent);
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let volume = match parse_volume_arg(&input) {
+        Ok(VolumeArg::Absolute(v)) => v,
+        Ok(VolumeArg::Relative(delta)) => match get_volume(device_id, scope) {
+            Ok((current, _)) => (current + delta).clamp(0.0, 1.0),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        },
         Err(e) => {
             eprintln!("{}", e);
             std::process::exit(1);
         }
     };
 
-    if let Err(e) = set_volume(device_id, volume) {
+    if let Err(e) = set_volume(device_id, volume, scope) {
         eprintln!("{}", e);
         std::process::exit(1);
     }
@@ -215,4 +793,15 @@ mod tests {
             Err(VolumeError::InvalidInput(_))
         ));
     }
+
+    #[test]
+    fn test_parse_volume_arg_absolute() {
+        assert_eq!(parse_volume_arg("50").unwrap(), VolumeArg::Absolute(0.5));
+    }
+
+    #[test]
+    fn test_parse_volume_arg_relative() {
+        assert_eq!(parse_volume_arg("+5").unwrap(), VolumeArg::Relative(0.05));
+        assert_eq!(parse_volume_arg("-10").unwrap(), VolumeArg::Relative(-0.1));
+    }
 }